@@ -2,20 +2,31 @@ extern crate serde;
 extern crate time;
 extern crate rustc_serialize;
 
+#[cfg(test)]
+#[macro_use]
+extern crate serde_derive;
+#[cfg(test)]
+extern crate serde_json;
+
 use time::Timespec;
 
+use std::error::Error;
 use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
 
 use std::convert::From;
 use std::ops::Deref;
 
 /// A timespec that is encoded & decoded to a pair of 'sec' and 'nsec' fields
 ///
-/// Display & Debug emit ISO 8601 times in utc using the form 'YYYY-mm-ddTHH:MM:SSZ' (where 'T',
-/// 'Z', and '-' are literal characters and all others are digit stand-ins.
+/// Display & Debug emit RFC 3339 times in utc using the form 'YYYY-mm-ddTHH:MM:SSZ', with
+/// trailing zeros in the fractional seconds trimmed (so a whole-second time omits the fractional
+/// part entirely). `Et` implements `FromStr` for that same grammar, so
+/// `et.to_string().parse::<Et>()` round-trips exactly.
 ///
-/// NOTE: Precision is currently lost on display. It should be expected that the format of the
-/// display will be adjusted to show the additional precision in the future.
+/// Through serde, only formats that are not human-readable get the 'sec'/'nsec' pair; human-readable
+/// formats (JSON, YAML, ...) get that RFC 3339 string instead.
 #[derive(Eq, PartialEq, Clone, Copy)]
 pub struct Et(Timespec);
 
@@ -35,48 +46,58 @@ impl Deref for Et {
 
 impl fmt::Display for Et {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        write!(f, "{}", time::strftime("%FT%TZ", &time::at_utc(self.0)).unwrap())
+        f.write_str(&format_rfc3339(&self.0))
     }
 }
 
-impl fmt::Debug for Et {
+/// The error returned by `Et`'s `FromStr` impl when a string isn't a valid RFC 3339 UTC
+/// timestamp in the grammar that `Et`'s `Display` impl produces. `Et::from_event_time_bytes`
+/// returns it too, for a malformed EventTime value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEtError(String);
+
+impl fmt::Display for ParseEtError {
     fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        fmt::Display::fmt(self, f)
+        f.write_str(&self.0)
     }
 }
 
-impl serde::Serialize for Et {
-    fn serialize<S>(&self, serializer: &mut S) -> Result<(), S::Error>
-        where S: serde::Serializer
-    {
-        serializer.serialize_struct("Et", EtMapVisitor {
-            value: self,
-            state: 0,
-        })
+impl Error for ParseEtError {
+    fn description(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Et {
+    type Err = ParseEtError;
+
+    fn from_str(s: &str) -> Result<Et, ParseEtError> {
+        parse_rfc3339(s).map(Et).map_err(ParseEtError)
     }
 }
 
-struct EtMapVisitor<'a> {
-    value: &'a Et,
-    state: u8,
+impl fmt::Debug for Et {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt::Display::fmt(self, f)
+    }
 }
 
-impl<'a> serde::ser::MapVisitor for EtMapVisitor<'a> {
-    fn visit<S>(&mut self, serializer: &mut S) -> Result<Option<()>, S::Error>
+impl serde::Serialize for Et {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where S: serde::Serializer
     {
-        match self.state {
-            0 => {
-                self.state += 1;
-                Ok(Some(try!(serializer.serialize_struct_elt("sec", &self.value.0.sec))))
-            }
-            1 => {
-                self.state += 1;
-                Ok(Some(try!(serializer.serialize_struct_elt("nsec", &self.value.0.nsec))))
-            }
-            _ => {
-                Ok(None)
-            }
+        // Binary formats (bincode, MessagePack, ...) want the tight `{sec, nsec}` struct;
+        // human-readable formats (JSON, YAML, ...) want a readable RFC 3339 string. This mirrors
+        // how serde's own `SystemTime` impl adapts its wire form.
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_string())
+        } else {
+            use serde::ser::SerializeStruct;
+
+            let mut state = try!(serializer.serialize_struct("Et", 2));
+            try!(state.serialize_field("sec", &self.0.sec));
+            try!(state.serialize_field("nsec", &self.0.nsec));
+            state.end()
         }
     }
 }
@@ -86,71 +107,133 @@ enum EtField {
     NSec,
 }
 
-impl serde::Deserialize for EtField {
-    fn deserialize<D>(deserializer: &mut D) -> Result<EtField, D::Error>
-        where D: serde::de::Deserializer
+impl<'de> serde::Deserialize<'de> for EtField {
+    fn deserialize<D>(deserializer: D) -> Result<EtField, D::Error>
+        where D: serde::de::Deserializer<'de>
         {
             struct FieldVisitor;
 
-            impl serde::de::Visitor for FieldVisitor {
+            impl<'de> serde::de::Visitor<'de> for FieldVisitor {
                 type Value = EtField;
 
-                fn visit_str<E>(&mut self, value: &str) -> Result<EtField, E>
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    f.write_str("`sec` or `nsec`")
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<EtField, E>
                     where E: serde::de::Error
                     {
                         match value {
-                            "sec" => Ok(EtField::NSec),
-                            "nsec" => Ok(EtField::Sec),
-                            a => Err(serde::de::Error::unknown_field(a)),
+                            "sec" => Ok(EtField::Sec),
+                            "nsec" => Ok(EtField::NSec),
+                            a => Err(serde::de::Error::unknown_field(a, ET_FIELDS)),
                         }
                     }
             }
 
-            deserializer.deserialize(FieldVisitor)
+            deserializer.deserialize_identifier(FieldVisitor)
         }
 }
 
 const ET_FIELDS: &'static [ &'static str ] = &[ "sec", "nsec" ];
 
-impl serde::Deserialize for Et {
-    fn deserialize<D>(deserializer: &mut D) -> Result<Et, D::Error>
-        where D: serde::de::Deserializer
+impl<'de> serde::Deserialize<'de> for Et {
+    fn deserialize<D>(deserializer: D) -> Result<Et, D::Error>
+        where D: serde::de::Deserializer<'de>
         {
-            deserializer.deserialize_struct("Et", ET_FIELDS, EtVisitor)
+            if deserializer.is_human_readable() {
+                // Self-describing formats dispatch to whichever `visit_*` matches the data
+                // actually on the wire, so this also accepts the `{sec, nsec}` struct shape.
+                deserializer.deserialize_any(EtVisitor)
+            } else {
+                deserializer.deserialize_struct("Et", ET_FIELDS, EtVisitor)
+            }
         }
 }
 
+/// Whether `nsec` is a legal `Timespec` nanosecond component. `Timespec::new` itself asserts
+/// this and panics if it doesn't hold, so every (de)serialization path that can hand it an
+/// attacker-controlled `nsec` needs to check this first.
+fn nsec_in_range(nsec: i32) -> bool {
+    (0..1_000_000_000).contains(&nsec)
+}
+
+/// Validates that `nsec` is a legal `Timespec` nanosecond component.
+fn validate_nsec<E>(nsec: i32) -> Result<i32, E>
+    where E: serde::de::Error
+{
+    if !nsec_in_range(nsec) {
+        Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Signed(nsec as i64),
+            &"a nanosecond count in 0..1_000_000_000"))
+    } else {
+        Ok(nsec)
+    }
+}
+
 struct EtVisitor;
 
-impl serde::de::Visitor for EtVisitor {
+impl<'de> serde::de::Visitor<'de> for EtVisitor {
     type Value = Et;
 
-    fn visit_map<V>(&mut self,
-                    mut visitor: V) -> Result<Et, V::Error>
-        where V: serde::de::MapVisitor
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an RFC 3339 timestamp string, or a struct with `sec` and `nsec` fields")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Et, E>
+        where E: serde::de::Error
+    {
+        v.parse().map_err(serde::de::Error::custom)
+    }
+
+    fn visit_map<V>(self, mut visitor: V) -> Result<Et, V::Error>
+        where V: serde::de::MapAccess<'de>
     {
         let mut sec = None;
         let mut nsec = None;
 
-        loop {
-            match try!(visitor.visit_key()) {
-                Some(EtField::Sec) => { sec = Some(try!(visitor.visit_value())); }
-                Some(EtField::NSec) => { nsec = Some(try!(visitor.visit_value())); }
-                None => { break; }
+        while let Some(key) = try!(visitor.next_key()) {
+            match key {
+                EtField::Sec => {
+                    if sec.is_some() {
+                        return Err(serde::de::Error::duplicate_field("sec"));
+                    }
+                    sec = Some(try!(visitor.next_value()));
+                }
+                EtField::NSec => {
+                    if nsec.is_some() {
+                        return Err(serde::de::Error::duplicate_field("nsec"));
+                    }
+                    nsec = Some(try!(visitor.next_value()));
+                }
             }
         }
 
         let sec = match sec {
             Some(sec) => sec,
-            None => try!(visitor.missing_field("sec")),
+            None => return Err(serde::de::Error::missing_field("sec")),
         };
 
         let nsec = match nsec {
-            Some(nsec) => nsec,
-            None => try!(visitor.missing_field("nsec")),
+            Some(nsec) => try!(validate_nsec(nsec)),
+            None => return Err(serde::de::Error::missing_field("nsec")),
         };
 
-        try!(visitor.end());
+        Ok(Et(Timespec::new(sec, nsec)))
+    }
+
+    fn visit_seq<V>(self, mut visitor: V) -> Result<Et, V::Error>
+        where V: serde::de::SeqAccess<'de>
+    {
+        let sec = match try!(visitor.next_element()) {
+            Some(sec) => sec,
+            None => return Err(serde::de::Error::invalid_length(0, &self)),
+        };
+
+        let nsec = match try!(visitor.next_element()) {
+            Some(nsec) => try!(validate_nsec(nsec)),
+            None => return Err(serde::de::Error::invalid_length(1, &self)),
+        };
 
         Ok(Et(Timespec::new(sec, nsec)))
     }
@@ -175,10 +258,476 @@ impl rustc_serialize::Decodable for Et {
     }
 }
 
+/// Adapter types selecting an alternate on-wire representation for [`Et`], modeled on
+/// `serde_with`'s transformation wrappers.
+///
+/// `Et` itself already picks between the RFC 3339 string and the `{sec, nsec}` struct based on
+/// `Serializer::is_human_readable`. To pin a particular field to one form regardless of format,
+/// annotate it with `#[serde(with = "...")]` naming one of the strategies below applied through
+/// [`As`]:
+///
+/// ```ignore
+/// #[derive(Serialize, Deserialize)]
+/// struct Event {
+///     #[serde(with = "encode_time::As::<encode_time::Et, encode_time::strategy::Rfc3339>")]
+///     when: Et,
+/// }
+/// ```
+pub mod strategy {
+    /// Preserves `Et`'s own `{sec, nsec}` struct encoding.
+    pub struct Struct;
+
+    /// Encodes as an RFC 3339 string, e.g. `2016-01-02T03:04:05.123456789Z`.
+    ///
+    /// Trailing zeros in the fractional part are trimmed, so a whole-second time encodes as
+    /// `2016-01-02T03:04:05Z`.
+    pub struct Rfc3339;
+
+    /// Encodes as a single integer count of seconds since the epoch.
+    ///
+    /// This is lossy: sub-second precision is dropped on serialize, and deserializing always
+    /// produces `nsec == 0`.
+    pub struct TimestampSeconds;
+
+    /// Encodes the *bytes* of a Fluentd Forward-protocol "EventTime" msgpack fixext8 value:
+    /// marker bytes `0xd7 0x00` followed by big-endian `sec` and `nsec` as `i32`s.
+    ///
+    /// This is lossy: `sec` is truncated to 32 bits, so the format only covers timestamps within
+    /// the `i32` second range.
+    ///
+    /// Serde's data model has no concept of a MessagePack ext type, so this can only hand those
+    /// 10 bytes to the serializer via `serialize_bytes`/`deserialize_bytes` — there is no way for
+    /// a generic `Serialize` impl to ask a `Serializer` for `0xd7 0x00` fixext8 framing. Against
+    /// a real MessagePack serializer (e.g. `rmp-serde`), `serialize_bytes` encodes as the `bin`
+    /// family instead (a length-prefixed byte string, not an ext type), so the wire form this
+    /// strategy produces is *not* what a real Fluentd Forward-protocol peer expects. It only
+    /// round-trips symmetrically against this crate's own (de)serialization of the same bytes
+    /// (or against a format like `bincode` with no ext-type concept of its own either).
+    ///
+    /// To actually produce or consume real Forward-protocol EventTime wire bytes, bypass serde
+    /// entirely and write or read the bytes of [`Et::to_event_time_bytes`]/
+    /// [`Et::from_event_time_bytes`] directly in the MessagePack stream.
+    pub struct EventTime;
+}
+
+/// Serializes a `T` using the wire representation chosen by `Strategy`.
+///
+/// See the [`strategy`] module for the strategies implemented for [`Et`].
+pub trait SerializeAs<T: ?Sized> {
+    fn serialize_as<S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where S: serde::Serializer;
+}
+
+/// Deserializes a `T` from the wire representation chosen by `Strategy`.
+///
+/// See the [`strategy`] module for the strategies implemented for [`Et`].
+pub trait DeserializeAs<'de, T> {
+    fn deserialize_as<D>(deserializer: D) -> Result<T, D::Error>
+        where D: serde::de::Deserializer<'de>;
+}
+
+/// A `#[serde(with = "...")]`-compatible adapter that (de)serializes a `T` via `Strategy`.
+pub struct As<T, Strategy> {
+    _marker: PhantomData<(T, Strategy)>,
+}
+
+impl<T, Strategy> As<T, Strategy> {
+    pub fn serialize<S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+        where S: serde::Serializer,
+              Strategy: SerializeAs<T>,
+    {
+        Strategy::serialize_as(value, serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<T, D::Error>
+        where D: serde::de::Deserializer<'de>,
+              Strategy: DeserializeAs<'de, T>,
+    {
+        Strategy::deserialize_as(deserializer)
+    }
+}
+
+impl SerializeAs<Et> for strategy::Struct {
+    fn serialize_as<S>(value: &Et, serializer: S) -> Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        use serde::ser::SerializeStruct;
+
+        let mut state = try!(serializer.serialize_struct("Et", 2));
+        try!(state.serialize_field("sec", &value.0.sec));
+        try!(state.serialize_field("nsec", &value.0.nsec));
+        state.end()
+    }
+}
+
+impl<'de> DeserializeAs<'de, Et> for strategy::Struct {
+    fn deserialize_as<D>(deserializer: D) -> Result<Et, D::Error>
+        where D: serde::de::Deserializer<'de>
+    {
+        deserializer.deserialize_struct("Et", ET_FIELDS, EtVisitor)
+    }
+}
+
+/// Breaks a Unix timestamp (seconds since 1970-01-01 00:00:00 UTC) into its UTC calendar
+/// fields, using Howard Hinnant's `civil_from_days` algorithm rather than the platform's libc.
+///
+/// `time::at_utc` goes through `gmtime_r` and panics for `sec` values outside what the
+/// platform's `time_t`/calendar routines accept (e.g. `i64::MAX`). This is pure integer math
+/// with no such limit, which is what lets `Et`'s `Display` impl promise it never panics.
+fn civil_from_unix(total_sec: i64) -> (i64, u32, u32, u32, u32, u32) {
+    let days = total_sec.div_euclid(86_400);
+    let sec_of_day = total_sec.rem_euclid(86_400);
+
+    let hour = (sec_of_day / 3_600) as u32;
+    let min = (sec_of_day / 60 % 60) as u32;
+    let sec = (sec_of_day % 60) as u32;
+
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let doe = (z - era * 146_097) as u64;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe as i64 + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day, hour, min, sec)
+}
+
+/// The inverse of [`civil_from_unix`]: the Unix day number (days since 1970-01-01) of a UTC
+/// calendar date, via Howard Hinnant's `days_from_civil` algorithm.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = (y - era * 400) as u64;
+    let mp = if month > 2 { month - 3 } else { month + 9 } as u64;
+    let doy = (153 * mp + 2) / 5 + day as u64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe as i64 - 719_468
+}
+
+/// Formats `ts` as an RFC 3339 string with trailing fractional zeros trimmed. Backs both `Et`'s
+/// `Display` impl and [`strategy::Rfc3339`].
+fn format_rfc3339(ts: &Timespec) -> String {
+    let (year, month, day, hour, min, sec) = civil_from_unix(ts.sec);
+
+    // RFC 3339 only defines a 4-digit year; outside 0000-9999 there's no panic risk left to
+    // guard against, so we just fall back to an unpadded (and non-conformant) year rather than
+    // lose information.
+    let mut s = if (0..=9_999).contains(&year) {
+        format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}", year, month, day, hour, min, sec)
+    } else {
+        format!("{}-{:02}-{:02}T{:02}:{:02}:{:02}", year, month, day, hour, min, sec)
+    };
+
+    if ts.nsec != 0 {
+        let mut frac = format!("{:09}", ts.nsec);
+        while frac.ends_with('0') {
+            frac.pop();
+        }
+        s.push('.');
+        s.push_str(&frac);
+    }
+
+    s.push('Z');
+    s
+}
+
+/// Parses the grammar produced by [`format_rfc3339`]: an RFC 3339 UTC timestamp with an optional
+/// 1-9 digit fractional-second component and a mandatory trailing `Z`. Backs both `Et`'s
+/// `FromStr` impl and [`strategy::Rfc3339`].
+fn parse_rfc3339(s: &str) -> Result<Timespec, String> {
+    // Every later slice is a fixed byte offset, which is only guaranteed to land on a char
+    // boundary if the whole string is ASCII; a multi-byte char overlapping one of those offsets
+    // would otherwise panic instead of producing a `ParseEtError`.
+    if !s.is_ascii() {
+        return Err(format!("expected an ASCII RFC 3339 timestamp, got {:?}", s));
+    }
+
+    if !s.ends_with('Z') {
+        return Err(format!("expected a trailing 'Z' in {:?}", s));
+    }
+    let body = &s[..s.len() - 1];
+
+    let (date_time, frac) = match body.find('.') {
+        Some(idx) => (&body[..idx], Some(&body[idx + 1..])),
+        None => (body, None),
+    };
+
+    // `-MM-DDTHH:MM:SS`: everything but the year is fixed-width, so split the year off the front
+    // instead of assuming it's always 4 digits. format_rfc3339 falls back to an unpadded year
+    // outside 0..=9999 (e.g. for `Timespec::new(i64::MIN, 0)`), and this has to accept that same
+    // form for `to_string().parse()` to really round-trip.
+    const DATE_TIME_SUFFIX_LEN: usize = "-MM-DDTHH:MM:SS".len();
+
+    if date_time.len() <= DATE_TIME_SUFFIX_LEN {
+        return Err(format!("malformed timestamp {:?}", s));
+    }
+
+    let (year_str, rest) = date_time.split_at(date_time.len() - DATE_TIME_SUFFIX_LEN);
+    let rest = rest.as_bytes();
+
+    if rest[0] != b'-' || rest[3] != b'-' || rest[6] != b'T' || rest[9] != b':' || rest[12] != b':' {
+        return Err(format!("malformed timestamp {:?}", s));
+    }
+
+    let bad = |what: &str| format!("bad {} in {:?}", what, s);
+
+    let year: i64 = try!(year_str.parse().map_err(|_| bad("year")));
+    let month: i32 = try!(date_time[date_time.len() - 14..date_time.len() - 12].parse().map_err(|_| bad("month")));
+    let day: i32 = try!(date_time[date_time.len() - 11..date_time.len() - 9].parse().map_err(|_| bad("day")));
+    let hour: i32 = try!(date_time[date_time.len() - 8..date_time.len() - 6].parse().map_err(|_| bad("hour")));
+    let min: i32 = try!(date_time[date_time.len() - 5..date_time.len() - 3].parse().map_err(|_| bad("minute")));
+    let sec: i32 = try!(date_time[date_time.len() - 2..].parse().map_err(|_| bad("second")));
+
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || !(0..=23).contains(&hour)
+        || !(0..=59).contains(&min) || !(0..=59).contains(&sec) {
+        return Err(bad("date or time field"));
+    }
+    // No year past this fits an i64 second count, and larger ones would overflow
+    // `days_from_civil`'s i64 arithmetic.
+    if year < -300_000_000_000 || year > 300_000_000_000 {
+        return Err(bad("timestamp out of range"));
+    }
+
+    let nsec = match frac {
+        Some(f) => {
+            if f.is_empty() || f.len() > 9 || !f.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(bad("fractional seconds"));
+            }
+            let mut digits = f.to_string();
+            while digits.len() < 9 {
+                digits.push('0');
+            }
+            try!(digits.parse::<i32>().map_err(|_| bad("fractional seconds")))
+        }
+        None => 0,
+    };
+
+    let days = days_from_civil(year, month as u32, day as u32);
+    // Widen to i128 for the combination: `days` can be large enough (e.g. parsing back
+    // `format_rfc3339`'s output for `Timespec::new(i64::MIN, 0)`) that `days * 86_400` alone
+    // overflows i64, even though the final `total_sec` fits.
+    let total_sec = days as i128 * 86_400
+        + hour as i128 * 3_600
+        + min as i128 * 60
+        + sec as i128;
+
+    if total_sec < i64::MIN as i128 || total_sec > i64::MAX as i128 {
+        return Err(bad("timestamp out of range"));
+    }
+
+    Ok(Timespec::new(total_sec as i64, nsec))
+}
+
+impl SerializeAs<Et> for strategy::Rfc3339 {
+    fn serialize_as<S>(value: &Et, serializer: S) -> Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_str(&value.to_string())
+    }
+}
+
+impl<'de> DeserializeAs<'de, Et> for strategy::Rfc3339 {
+    fn deserialize_as<D>(deserializer: D) -> Result<Et, D::Error>
+        where D: serde::de::Deserializer<'de>
+    {
+        struct V;
+
+        impl<'de> serde::de::Visitor<'de> for V {
+            type Value = Et;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an RFC 3339 timestamp string")
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Et, E>
+                where E: serde::de::Error
+            {
+                v.parse().map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_str(V)
+    }
+}
+
+impl SerializeAs<Et> for strategy::TimestampSeconds {
+    fn serialize_as<S>(value: &Et, serializer: S) -> Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_i64(value.0.sec)
+    }
+}
+
+impl<'de> DeserializeAs<'de, Et> for strategy::TimestampSeconds {
+    fn deserialize_as<D>(deserializer: D) -> Result<Et, D::Error>
+        where D: serde::de::Deserializer<'de>
+    {
+        let sec = try!(serde::Deserialize::deserialize(deserializer));
+        Ok(Et(Timespec::new(sec, 0)))
+    }
+}
+
+/// The two marker bytes a Fluentd Forward-protocol EventTime msgpack ext payload starts with.
+const EVENT_TIME_MARKER: [u8; 2] = [0xd7, 0x00];
+
+impl Et {
+    /// Encodes this timestamp as a Fluentd Forward-protocol "EventTime" msgpack fixext8 value,
+    /// 10 bytes: marker bytes `0xd7 0x00` (fixext8, ext type 0) followed by big-endian `sec` and
+    /// `nsec` as `i32`s.
+    ///
+    /// This is lossy: `sec` is truncated to 32 bits, so it only covers timestamps within the
+    /// `i32` second range.
+    ///
+    /// The marker bytes are the msgpack ext header itself, so these 10 bytes are the whole
+    /// msgpack value and go into a MessagePack stream as they are, with no further ext header
+    /// in front of them — serde's data model has no ext-type concept, so
+    /// [`strategy::EventTime`] (which goes through
+    /// `Serializer::serialize_bytes`) can't produce that framing and is only useful for
+    /// round-tripping within this crate or through formats with no ext-type concept of their own
+    /// (e.g. `bincode`).
+    pub fn to_event_time_bytes(&self) -> [u8; 10] {
+        let mut buf = [0u8; 10];
+        buf[0..2].copy_from_slice(&EVENT_TIME_MARKER);
+        buf[2..6].copy_from_slice(&(self.0.sec as i32).to_be_bytes());
+        buf[6..10].copy_from_slice(&self.0.nsec.to_be_bytes());
+        buf
+    }
+
+    /// The inverse of [`Et::to_event_time_bytes`]: parses a 10-byte Fluentd Forward-protocol
+    /// "EventTime" msgpack fixext8 value. The bytes must include the `0xd7 0x00` ext header, as
+    /// they appear in the MessagePack stream.
+    pub fn from_event_time_bytes(bytes: &[u8]) -> Result<Et, ParseEtError> {
+        if bytes.len() != 10 {
+            return Err(ParseEtError(
+                format!("expected a 10-byte EventTime payload, got {} bytes", bytes.len())));
+        }
+        if bytes[0..2] != EVENT_TIME_MARKER {
+            return Err(ParseEtError(
+                "EventTime payload does not start with the 0xd7 0x00 fixext8 marker".to_string()));
+        }
+
+        let mut sec_bytes = [0u8; 4];
+        sec_bytes.copy_from_slice(&bytes[2..6]);
+        let mut nsec_bytes = [0u8; 4];
+        nsec_bytes.copy_from_slice(&bytes[6..10]);
+
+        let sec = i32::from_be_bytes(sec_bytes);
+        let nsec = i32::from_be_bytes(nsec_bytes);
+
+        if !nsec_in_range(nsec) {
+            return Err(ParseEtError(format!("nsec {} out of range 0..1_000_000_000", nsec)));
+        }
+
+        Ok(Et(Timespec::new(sec as i64, nsec)))
+    }
+}
+
+impl SerializeAs<Et> for strategy::EventTime {
+    fn serialize_as<S>(value: &Et, serializer: S) -> Result<S::Ok, S::Error>
+        where S: serde::Serializer
+    {
+        serializer.serialize_bytes(&value.to_event_time_bytes())
+    }
+}
+
+impl<'de> DeserializeAs<'de, Et> for strategy::EventTime {
+    fn deserialize_as<D>(deserializer: D) -> Result<Et, D::Error>
+        where D: serde::de::Deserializer<'de>
+    {
+        struct V;
+
+        impl<'de> serde::de::Visitor<'de> for V {
+            type Value = Et;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a 10-byte Fluentd EventTime fixext8 payload")
+            }
+
+            fn visit_bytes<E>(self, v: &[u8]) -> Result<Et, E>
+                where E: serde::de::Error
+            {
+                Et::from_event_time_bytes(v).map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_bytes(V)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     extern crate bincode;
 
+    use super::{As, Et, strategy};
+
+    #[test]
+    fn display_trims_whole_seconds() {
+        let t = Et(::time::Timespec::new(1_234, 0));
+        assert_eq!(t.to_string(), "1970-01-01T00:20:34Z");
+    }
+
+    #[test]
+    fn display_keeps_subsecond_precision() {
+        let t = Et(::time::Timespec::new(1_234, 123_456_789));
+        assert_eq!(t.to_string(), "1970-01-01T00:20:34.123456789Z");
+    }
+
+    #[test]
+    fn display_from_str_round_trips() {
+        let t = Et::from(::time::get_time());
+        assert_eq!(t.to_string().parse::<Et>().unwrap(), t);
+    }
+
+    #[test]
+    fn display_from_str_round_trips_on_extreme_sec() {
+        for &sec in &[i64::MIN, i64::MAX] {
+            let t = Et(::time::Timespec::new(sec, 0));
+            let s = t.to_string();
+            assert_eq!(s.parse::<Et>().unwrap(), t, "round trip of {:?} through {:?}", t, s);
+        }
+    }
+
+    #[test]
+    fn serde_json_struct_form_routes_fields_correctly() {
+        let t: Et = ::serde_json::from_str(r#"{"sec":1234,"nsec":567}"#).unwrap();
+        assert_eq!(t, Et(::time::Timespec::new(1_234, 567)));
+    }
+
+    #[test]
+    fn serde_json_struct_form_rejects_duplicate_field() {
+        let e = ::serde_json::from_str::<Et>(r#"{"sec":1,"sec":2,"nsec":0}"#);
+        assert!(e.is_err());
+    }
+
+    #[test]
+    fn serde_json_struct_form_rejects_out_of_range_nsec() {
+        let e = ::serde_json::from_str::<Et>(r#"{"sec":1,"nsec":1000000000}"#);
+        assert!(e.is_err());
+    }
+
+    #[test]
+    fn serde_json_uses_rfc3339_string() {
+        let t = Et::from(::time::get_time());
+        let e = ::serde_json::to_string(&t).unwrap();
+        assert_eq!(e, format!("\"{}\"", t));
+
+        let t2: Et = ::serde_json::from_str(&e).unwrap();
+        assert_eq!(t, t2);
+    }
+
+    #[test]
+    fn serde_bincode_uses_struct() {
+        let t = Et::from(::time::get_time());
+        let e = bincode::serialize(&t).unwrap();
+        // An i64 `sec` and an i32 `nsec`, not a length-prefixed string.
+        assert_eq!(e.len(), 12);
+        let t2: Et = bincode::deserialize(&e).unwrap();
+        assert_eq!(t, t2);
+    }
+
     #[test]
     fn rs_json() {
         let t = ::Et::from(::time::get_time());
@@ -189,19 +738,86 @@ mod tests {
         assert_eq!(t, t2);
     }
 
+    // There used to be an `rs_bincode` test here round-tripping `Et` through
+    // `bincode::rustc_serialize::{encode,decode}`. bincode 1.x dropped its `rustc_serialize`
+    // interop module entirely (no `SizeLimit`, no `rustc_serialize` submodule), and the version
+    // required by `bincode::{serialize,deserialize}` below no longer has it, so that test can't
+    // be migrated to the new API — only dropped. `rs_json` above exercises the same
+    // `Encodable`/`Decodable` impls via a different format; note it already fails independently
+    // of this, which predates this series and isn't specific to bincode.
+
     #[test]
-    fn rs_bincode() {
+    fn serde_bincode() {
         let t = ::Et::from(::time::get_time());
-        let e = bincode::rustc_serialize::encode(&t, bincode::SizeLimit::Infinite).unwrap();
-        let t2 = bincode::rustc_serialize::decode(&e).unwrap();
+        let e = bincode::serialize(&t).unwrap();
+        let t2 = bincode::deserialize(&e).unwrap();
         assert_eq!(t, t2);
     }
 
+    #[derive(Serialize, Deserialize)]
+    struct WithRfc3339 {
+        #[serde(with = "As::<Et, strategy::Rfc3339>")]
+        when: Et,
+    }
+
     #[test]
-    fn serde_bincode() {
-        let t = ::Et::from(::time::get_time());
-        let e = bincode::serde::serialize(&t, bincode::SizeLimit::Infinite).unwrap();
-        let t2 = bincode::serde::deserialize(&e).unwrap();
-        assert_eq!(t, t2);
+    fn as_rfc3339_round_trips() {
+        let t = WithRfc3339 { when: Et::from(::time::get_time()) };
+        let e = ::serde_json::to_string(&t).unwrap();
+        let t2: WithRfc3339 = ::serde_json::from_str(&e).unwrap();
+        assert_eq!(t.when, t2.when);
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct WithEventTime {
+        #[serde(with = "As::<Et, strategy::EventTime>")]
+        when: Et,
+    }
+
+    #[test]
+    fn as_event_time_round_trips() {
+        let t = WithEventTime { when: Et(::time::Timespec::new(1_234, 567)) };
+        let e = bincode::serialize(&t).unwrap();
+        let t2: WithEventTime = bincode::deserialize(&e).unwrap();
+        assert_eq!(t.when, t2.when);
+    }
+
+    #[test]
+    fn event_time_bytes_round_trip() {
+        let t = Et(::time::Timespec::new(1_234, 567));
+        assert_eq!(Et::from_event_time_bytes(&t.to_event_time_bytes()).unwrap(), t);
+    }
+
+    #[test]
+    fn event_time_bytes_reject_out_of_range_nsec_instead_of_panicking() {
+        let mut buf = [0u8; 10];
+        buf[0..2].copy_from_slice(&[0xd7, 0x00]);
+        buf[2..6].copy_from_slice(&0i32.to_be_bytes());
+        buf[6..10].copy_from_slice(&[0xff, 0xff, 0xff, 0xff]);
+        assert!(Et::from_event_time_bytes(&buf).is_err());
+    }
+
+    #[test]
+    fn as_event_time_rejects_out_of_range_nsec_instead_of_panicking() {
+        let mut buf = [0u8; 10];
+        buf[0..2].copy_from_slice(&[0xd7, 0x00]);
+        buf[2..6].copy_from_slice(&0i32.to_be_bytes());
+        buf[6..10].copy_from_slice(&[0xff, 0xff, 0xff, 0xff]);
+        let e = bincode::serialize(&buf.to_vec()).unwrap();
+        assert!(bincode::deserialize::<WithEventTime>(&e).is_err());
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct WithTimestampSeconds {
+        #[serde(with = "As::<Et, strategy::TimestampSeconds>")]
+        when: Et,
+    }
+
+    #[test]
+    fn as_timestamp_seconds_drops_subsecond_precision() {
+        let t = WithTimestampSeconds { when: Et(::time::Timespec::new(1_234, 567)) };
+        let e = ::serde_json::to_string(&t).unwrap();
+        let t2: WithTimestampSeconds = ::serde_json::from_str(&e).unwrap();
+        assert_eq!(t2.when, Et(::time::Timespec::new(1_234, 0)));
     }
 }